@@ -1,8 +1,12 @@
 use std::path::PathBuf;
 
-use tracing::debug;
+use tracing::{debug, warn, Subscriber};
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{self};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{self, EnvFilter};
+
+const DEFAULT_FILTER: &str = "forge=debug";
 
 pub fn init_tracing(log_path: PathBuf) -> anyhow::Result<Guard> {
     debug!(path = %log_path.display(), "Initializing logging system in JSON format");
@@ -10,22 +14,137 @@ pub fn init_tracing(log_path: PathBuf) -> anyhow::Result<Guard> {
     let append = tracing_appender::rolling::daily(log_path, "forge.log");
     let (non_blocking, guard) = tracing_appender::non_blocking(append);
 
+    let forge_log = env_value("FORGE_LOG");
+    let rust_log = env_value("RUST_LOG");
+
+    subscriber(
+        env_filter(forge_log.as_deref(), rust_log.as_deref()),
+        non_blocking,
+    )
+    .init();
+
+    let (var, directives) = match (forge_log.as_deref(), rust_log.as_deref()) {
+        (Some(directives), _) => ("FORGE_LOG", Some(directives)),
+        (None, directives) => ("RUST_LOG", directives),
+    };
+    if let Some(Err(err)) = directives.map(EnvFilter::try_new) {
+        warn!(%err, var, "Invalid log filter, falling back to {DEFAULT_FILTER}");
+    }
+
+    debug!("JSON logging system initialized successfully");
+    Ok(Guard(guard))
+}
+
+fn env_value(name: &str) -> Option<String> {
+    std::env::var_os(name).map(|value| value.to_string_lossy().into_owned())
+}
+
+/// Builds the log filter from `FORGE_LOG`, using `RUST_LOG` only when
+/// `FORGE_LOG` is unset. Falls back to `forge=debug` when neither is set or the
+/// chosen value fails to parse.
+fn env_filter(forge_log: Option<&str>, rust_log: Option<&str>) -> EnvFilter {
+    forge_log
+        .or(rust_log)
+        .and_then(|directives| EnvFilter::try_new(directives).ok())
+        .unwrap_or_else(|| EnvFilter::new(DEFAULT_FILTER))
+}
+
+fn subscriber<W>(filter: EnvFilter, writer: W) -> impl Subscriber + Send + Sync + 'static
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
     tracing_subscriber::fmt()
         .json()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_env("FORGE_LOG")
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("forge=debug")),
-        )
+        .with_env_filter(filter)
         .with_timer(tracing_subscriber::fmt::time::uptime())
         .with_thread_ids(false)
         .with_target(false)
         .with_file(true)
         .with_line_number(true)
-        .with_writer(non_blocking)
-        .init();
-
-    debug!("JSON logging system initialized successfully");
-    Ok(Guard(guard))
+        .with_writer(writer)
+        .finish()
 }
 
 pub struct Guard(#[allow(dead_code)] WorkerGuard);
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_env_filter_prefers_forge_log() {
+        let actual = env_filter(Some("forge=trace"), Some("forge=warn")).to_string();
+        let expected = "forge=trace";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_env_filter_uses_rust_log_when_forge_log_unset() {
+        let actual = env_filter(None, Some("forge=warn")).to_string();
+        let expected = "forge=warn";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_env_filter_default() {
+        let actual = env_filter(None, None).to_string();
+        let expected = DEFAULT_FILTER;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_env_filter_invalid_forge_log_ignores_rust_log() {
+        let actual = env_filter(Some("forge=notalevel"), Some("forge=warn")).to_string();
+        let expected = DEFAULT_FILTER;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_env_filter_invalid_rust_log() {
+        let actual = env_filter(None, Some("forge=notalevel")).to_string();
+        let expected = DEFAULT_FILTER;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_subscriber_records_tracing_and_log_macros() {
+        let fixture = Buffer::default();
+        let subscriber = subscriber(env_filter(None, None), fixture.clone());
+
+        {
+            let _guard = subscriber.set_default();
+            tracing::info!("from tracing");
+            ::log::info!("from log");
+        }
+
+        let actual = String::from_utf8(fixture.0.lock().unwrap().clone()).unwrap();
+        assert!(actual.contains("from tracing"), "{actual}");
+        assert!(actual.contains("from log"), "{actual}");
+    }
+}